@@ -70,6 +70,15 @@ pub trait DefaultPosition {
 // Implementing DefaultPosition for Point to test associated constants from traits
 impl DefaultPosition for Point {}
 
+pub struct Origin3D;
+
+impl DefaultPosition for Origin3D {
+    fn default_pos() -> (i32, i32) {
+        // Overrides DefaultPosition's provided default instead of inheriting it
+        (100, 100)
+    }
+}
+
 pub struct Circle {
     pub center: Point,
     pub radius: f64,
@@ -81,3 +90,100 @@ impl Shape for Circle {
         std::f64::consts::PI * self.radius * self.radius
     }
 }
+
+// To test enum variants (unit, with explicit discriminants) as distinct code units
+pub enum StatusCode {
+    Ok = 200,
+    NotFound = 404,
+    ServerError = 500,
+}
+
+// To test tuple structs and positional field access resolution (e.g. `pair.0`)
+pub struct Pair(pub i32, pub i32);
+
+impl Pair {
+    pub fn sum(&self) -> i32 {
+        self.0 + self.1
+    }
+}
+
+// To test positional field access through an arbitrary parameter binding,
+// not just `self` inside the tuple struct's own inherent impl
+pub fn sum_pair(p: &Pair) -> i32 {
+    p.0 + p.1
+}
+
+// To test derive-attribute awareness: these derives should synthesize
+// implied trait implementations (Debug, Clone, PartialEq) for Vector2
+// even though no explicit `impl` blocks are written below.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+// Calls the derived Clone::clone, so the synthesized impl has a call site to resolve
+pub fn cloned_vector(v: &Vector2) -> Vector2 {
+    v.clone()
+}
+
+// To test derive-attribute awareness for Default: the synthesized impl has
+// no written source, but Count::default() is a call site to resolve
+#[derive(Default)]
+pub struct Count {
+    pub value: i32,
+}
+
+pub fn zero_count() -> Count {
+    Count::default()
+}
+
+// To test associated types and generic items with trait bounds
+pub trait Container {
+    type Item;
+    fn get(&self, index: usize) -> Option<&Self::Item>;
+}
+
+pub struct Wrapper<T: Clone> {
+    pub items: Vec<T>,
+}
+
+impl<T: Clone> Container for Wrapper<T> {
+    type Item = T;
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+}
+
+// To test struct update (functional record update) syntax resolution,
+// i.e. `..ORIGIN` referencing Point's fields not listed explicitly
+pub fn shifted_origin(dx: i32, dy: i32) -> Point {
+    Point { x: dx, y: dy, ..ORIGIN }
+}
+
+// To test `where`-clause handling: bounds written after the generic
+// parameter list rather than inline in `<...>`, on a struct, a trait,
+// and an impl of that trait for that struct.
+pub struct Labeled<T>
+where
+    T: Clone,
+{
+    pub value: T,
+}
+
+pub trait Summary<T>
+where
+    T: Clone,
+{
+    fn summarize(&self) -> T;
+}
+
+impl<T> Summary<T> for Labeled<T>
+where
+    T: Clone,
+{
+    fn summarize(&self) -> T {
+        self.value.clone()
+    }
+}